@@ -1,8 +1,8 @@
 use crate::{ShellError, Span};
 use serde::{Deserialize, Serialize};
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Condvar, Mutex,
 };
 
 /// Used to check for signals to suspend or terminate the execution of Nushell code.
@@ -12,6 +12,8 @@ use std::sync::{
 pub struct Signals {
     pub interrupt: Option<Arc<AtomicBool>>, // Tracks Ctrl+C (SIGINT)
     pub pause: Option<Arc<AtomicBool>>,     // Tracks Ctrl+Z (SIGTSTP)
+    depth: Option<Arc<AtomicUsize>>,        // Nesting depth of open `scope()` guards
+    resume: Option<Arc<(Mutex<()>, Condvar)>>, // Wakes `wait_if_paused` on SIGCONT
 }
 
 impl Signals {
@@ -21,6 +23,8 @@ impl Signals {
     pub const EMPTY: Self = Signals {
         interrupt: None,
         pause: None,
+        depth: None,
+        resume: None,
     };
 
     /// Create a new [`Signals`] with `ctrlc` and `ctrlz` as signal sources.
@@ -31,6 +35,8 @@ impl Signals {
         Self {
             interrupt: Some(ctrlc),
             pause: Some(ctrlz),
+            depth: Some(Arc::new(AtomicUsize::new(0))),
+            resume: Some(Arc::new((Mutex::new(()), Condvar::new()))),
         }
     }
 
@@ -98,8 +104,52 @@ impl Signals {
             .is_some_and(|b| b.load(Ordering::Acquire))
     }
 
+    /// Clears a pause and wakes any thread parked in [`wait_if_paused`](Self::wait_if_paused).
+    ///
+    /// Called when SIGCONT is delivered after a real SIGTSTP-driven suspend.
+    pub fn trigger_resume(&self) {
+        if let Some(pause) = &self.pause {
+            pause.store(false, Ordering::SeqCst);
+        }
+        if let Some(resume) = &self.resume {
+            let (mutex, condvar) = &**resume;
+            // Taking the lock orders this wakeup after any waiter's pause check.
+            let _guard = mutex.lock().unwrap();
+            condvar.notify_all();
+        }
+    }
+
+    /// Blocks the calling thread until a pending pause is lifted.
+    ///
+    /// Unlike [`check`](Self::check), which errors out with
+    /// `ShellError::SuspendedByUser`, this parks the thread on a [`Condvar`]
+    /// so a cooperative command can genuinely suspend in place and resume
+    /// exactly where it left off once SIGCONT arrives.
+    ///
+    /// Must not be called from whichever thread dequeues SIGCONT and calls
+    /// [`trigger_resume`](Self::trigger_resume) (that's a self-deadlock);
+    /// `ctrl_protection`'s Ctrl-Z key-polling thread, a separate thread from
+    /// its signal listener, is the one call site today.
+    pub fn wait_if_paused(&self) {
+        let (Some(pause), Some(resume)) = (&self.pause, &self.resume) else {
+            return;
+        };
+        let (mutex, condvar) = &**resume;
+        let guard = mutex.lock().unwrap();
+        let _guard = condvar
+            .wait_while(guard, |_| pause.load(Ordering::Acquire))
+            .unwrap();
+    }
+
     /// Resets both interrupt and pause signals.
+    ///
+    /// No-op while any [`scope`](Self::scope) guard, the outermost included, is
+    /// still open, so an inner block's reset can't swallow a signal meant for
+    /// an enclosing loop; the outermost caller resets once it unwinds.
     pub fn reset(&self) {
+        if self.depth.as_deref().is_some_and(|d| d.load(Ordering::Acquire) > 0) {
+            return;
+        }
         if let Some(interrupt) = &self.interrupt {
             interrupt.store(false, Ordering::Relaxed);
         }
@@ -111,11 +161,239 @@ impl Signals {
     pub(crate) fn is_empty(&self) -> bool {
         self.interrupt.is_none() && self.pause.is_none()
     }
+
+    /// Enter a nested registration scope, returning an RAII guard that
+    /// releases it on drop. While any scope is open, [`reset`](Self::reset)
+    /// defers to the outermost one (see there).
+    ///
+    /// Used today to wrap each dispatched [`SignalAction`] handler in
+    /// `ctrl_protection` (so a handler's own `reset` can't swallow a signal
+    /// meant for the pipeline it preempted); the block evaluator should wrap
+    /// each nested block/closure invocation the same way once it exists here.
+    pub fn scope(&self) -> SignalsScope {
+        if let Some(depth) = &self.depth {
+            depth.fetch_add(1, Ordering::AcqRel);
+        }
+        SignalsScope {
+            depth: self.depth.clone(),
+        }
+    }
+
+    /// Wrap `reader` so that every [`Read::read`] call first checks this
+    /// [`Signals`] for an interrupt, failing fast instead of blocking inside
+    /// the inner reader until Ctrl-C is next polled.
+    pub fn interruptible_reader<R>(&self, reader: R) -> Interruptible<R> {
+        Interruptible {
+            inner: reader,
+            interrupt: self.interrupt.clone(),
+        }
+    }
+
+    /// Wrap `writer` so that every [`Write::write`] call first checks this
+    /// [`Signals`] for an interrupt, the `Write` counterpart of
+    /// [`interruptible_reader`](Self::interruptible_reader).
+    pub fn interruptible_writer<W>(&self, writer: W) -> Interruptible<W> {
+        Interruptible {
+            inner: writer,
+            interrupt: self.interrupt.clone(),
+        }
+    }
+}
+
+/// A [`Read`](std::io::Read)/[`Write`](std::io::Write) adapter, built with
+/// [`Signals::interruptible_reader`]/[`interruptible_writer`](Signals::interruptible_writer),
+/// that fails with [`ErrorKind::Other`](std::io::ErrorKind::Other) (see
+/// [`is_interrupt`](Self::is_interrupt)) instead of blocking once its
+/// [`Signals`] is interrupted.
+#[derive(Debug)]
+pub struct Interruptible<T> {
+    inner: T,
+    interrupt: Option<Arc<AtomicBool>>,
+}
+
+/// Marker set as the source of the `io::Error` an [`Interruptible`] raises.
+///
+/// Deliberately not `ErrorKind::Interrupted`: the stdlib treats that kind as
+/// "safe to retry" and `read_to_end`/`read_to_string`, `write_all`, and
+/// `io::copy` all swallow it and loop instead of propagating it, which would
+/// defeat the adapter entirely on exactly the streaming helpers it targets.
+#[derive(Debug)]
+struct Interrupted;
+
+impl std::fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "nushell interrupt signal received")
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
+impl<T> Interruptible<T> {
+    fn check(&self) -> std::io::Result<()> {
+        if self
+            .interrupt
+            .as_deref()
+            .is_some_and(|b| b.load(Ordering::Acquire))
+        {
+            Err(std::io::Error::new(std::io::ErrorKind::Other, Interrupted))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether `err` was raised by an [`Interruptible`] because its
+    /// [`Signals`] was interrupted, as opposed to a genuine I/O failure —
+    /// callers driving the adapter through a retry-prone combinator should
+    /// check this rather than assume `ErrorKind` alone tells them apart.
+    pub fn is_interrupt(err: &std::io::Error) -> bool {
+        err.get_ref().is_some_and(|e| e.is::<Interrupted>())
+    }
+
+    /// Consume the adapter, returning the wrapped reader or writer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: std::io::Read> std::io::Read for Interruptible<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.check()?;
+        self.inner.read(buf)
+    }
+}
+
+impl<T: std::io::Write> std::io::Write for Interruptible<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.check()?;
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.check()?;
+        self.inner.flush()
+    }
+}
+
+/// RAII guard returned by [`Signals::scope`]; decrements the registration
+/// depth on drop.
+#[derive(Debug)]
+pub struct SignalsScope {
+    depth: Option<Arc<AtomicUsize>>,
+}
+
+impl Drop for SignalsScope {
+    fn drop(&mut self) {
+        if let Some(depth) = &self.depth {
+            depth.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+/// The raw signal and sender of a [`SignalAction`]-triggering delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignalOrigin {
+    /// The raw signal number that was delivered (e.g. `libc::SIGINT`).
+    pub signal: i32,
+    /// PID of the process that sent the signal, if the kernel reported one.
+    pub pid: Option<i32>,
+    /// UID of the sender, if the kernel reported one.
+    pub uid: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignalAction {
-    Interrupt,
+    Interrupt(Option<SignalOrigin>),
     Reset,
-    Pause,
+    Pause(Option<SignalOrigin>),
+    /// A reload was requested (e.g. via SIGHUP) without interrupting the running pipeline.
+    Reload(Option<SignalOrigin>),
+    /// The process was resumed after a real suspend (SIGCONT following SIGTSTP).
+    Resume(Option<SignalOrigin>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    fn signals() -> Signals {
+        Signals::new(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    #[test]
+    fn interruptible_reader_passes_through_until_interrupted() {
+        let signals = signals();
+        let mut reader = signals.interruptible_reader(&b"hello"[..]);
+
+        let mut buf = [0; 5];
+        assert_eq!(reader.read(&mut buf).unwrap(), 5);
+
+        signals.trigger();
+        let err = reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+        assert!(Interruptible::<&[u8]>::is_interrupt(&err));
+    }
+
+    #[test]
+    fn interruptible_writer_fails_fast_once_interrupted() {
+        let signals = signals();
+        let mut writer = signals.interruptible_writer(Vec::new());
+        writer.write_all(b"ok").unwrap();
+
+        signals.trigger();
+        let err = writer.write(b"more").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+        assert!(Interruptible::<Vec<u8>>::is_interrupt(&err));
+    }
+
+    #[test]
+    fn interrupted_reader_is_not_silently_retried_by_io_copy() {
+        // `io::copy` retries `ErrorKind::Interrupted` forever, which is
+        // exactly the failure mode this adapter must not trigger: if it ever
+        // regresses to that kind, this test hangs instead of failing cleanly.
+        let signals = signals();
+        let mut reader = signals.interruptible_reader(&b"hello world"[..]);
+        signals.trigger();
+
+        let mut sink = Vec::new();
+        let err = std::io::copy(&mut reader, &mut sink).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+        assert!(Interruptible::<&[u8]>::is_interrupt(&err));
+    }
+
+    #[test]
+    fn reset_defers_to_outermost_scope() {
+        let signals = signals();
+        signals.trigger();
+
+        let outer = signals.scope();
+        let inner = signals.scope();
+
+        // A nested scope is still open, so reset is a no-op.
+        signals.reset();
+        assert!(signals.interrupted());
+
+        drop(inner);
+        signals.reset();
+        assert!(signals.interrupted());
+
+        drop(outer);
+        signals.reset();
+        assert!(!signals.interrupted());
+    }
+
+    #[test]
+    fn scope_drop_alone_does_not_clear_an_unacknowledged_interrupt() {
+        let signals = signals();
+        let scope = signals.scope();
+        signals.trigger();
+        drop(scope);
+
+        // Dropping the guard must not silently swallow the interrupt; only an
+        // explicit `reset` (the engine acknowledging it) can clear it.
+        assert!(signals.interrupted());
+    }
 }
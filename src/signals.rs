@@ -2,20 +2,88 @@ use crossterm::{
     event::{self, KeyCode, KeyEvent},
     terminal, ExecutableCommand as _,
 };
-use nu_protocol::{engine::EngineState, Handlers, SignalAction, Signals};
+use nu_protocol::{engine::EngineState, Handlers, SignalAction, SignalOrigin, Signals};
 use reedline::KeyModifiers;
-use signal_hook::consts::{SIGINT, SIGTERM, SIGTSTP};
-use signal_hook::iterator::Signals as SignalHook;
+use signal_hook::consts::{SIGCONT, SIGHUP, SIGINT, SIGTERM, SIGTSTP};
+use signal_hook::iterator::exfiltrator::origin::{Origin, WithOrigin};
+use signal_hook::iterator::SignalsInfo;
+use std::io::Write as _;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::{io, thread, time::Duration};
+use std::{io, thread, time::Duration, time::Instant};
 
 static INTERRUPT_FLAG: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+// Timestamp of the most recent SIGINT/Ctrl-C, used to detect a double-tap.
+// `interrupt_clone` (a separate atomic, not this) is what tracks whether the
+// engine has acknowledged it via `Signals::reset`.
+static PENDING_INTERRUPT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// A second Ctrl-C landing within this window of the first, while the engine
+/// still hasn't acknowledged it, is treated as a force-quit request rather
+/// than a regular interrupt.
+const DOUBLE_TAP_WINDOW: Duration = Duration::from_millis(1500);
+
+/// Whether `now`, the instant of a just-received SIGINT, counts as an
+/// unacknowledged double-tap given `first_unacknowledged` (the instant of the
+/// most recent SIGINT still pending acknowledgement, if any) and whether the
+/// engine has already reset the interrupt flag.
+fn is_unacknowledged_double_tap(
+    first_unacknowledged: Option<Instant>,
+    still_unacknowledged: bool,
+    now: Instant,
+) -> bool {
+    still_unacknowledged
+        && first_unacknowledged.is_some_and(|first| now.duration_since(first) <= DOUBLE_TAP_WINDOW)
+}
+
+/// Force a clean shutdown after a second Ctrl-C, restoring the terminal first
+/// so the user isn't left in raw mode with a wedged shell.
+fn force_shutdown() -> ! {
+    let _ = terminal::disable_raw_mode();
+    let mut stdout = io::stdout();
+    let _ = stdout.execute(crossterm::terminal::Clear(
+        crossterm::terminal::ClearType::All,
+    ));
+    let _ = stdout.flush();
+    std::process::exit(130);
+}
+
+/// Truly suspend the process (job control style), handing the terminal back
+/// to the parent shell until a SIGCONT wakes us back up.
+fn suspend_to_background() {
+    let _ = terminal::disable_raw_mode();
+    // SAFETY: `raise` only sends a signal to the current process; it has no
+    // memory-safety preconditions.
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+}
+
+/// Restore the terminal after a SIGCONT wakes us back up from a real suspend.
+fn resume_from_background() {
+    let _ = terminal::enable_raw_mode();
+    let mut stdout = io::stdout();
+    let _ = stdout.execute(crossterm::terminal::Clear(
+        crossterm::terminal::ClearType::All,
+    ));
+    let _ = stdout.flush();
+}
+
+/// Turn signal-hook's origin info into the `nu-protocol` metadata threaded
+/// through `SignalAction`.
+fn signal_origin(info: &Origin) -> SignalOrigin {
+    SignalOrigin {
+        signal: info.signal,
+        pid: info.process.map(|process| process.pid),
+        uid: info.process.map(|process| process.uid),
+    }
+}
 
 pub(crate) fn ctrl_protection(engine_state: &mut EngineState) {
     let pause_flag = Arc::new(AtomicBool::new(false));
     let interrupt = Arc::new(AtomicBool::new(false));
-    engine_state.set_signals(Signals::new(interrupt.clone(), pause_flag.clone()));
+    let signals = Signals::new(interrupt.clone(), pause_flag.clone());
+    engine_state.set_signals(signals.clone());
 
     let signal_handlers = Handlers::new();
     engine_state.signal_handlers = Some(signal_handlers.clone());
@@ -27,23 +95,75 @@ pub(crate) fn ctrl_protection(engine_state: &mut EngineState) {
 
     let interrupt_clone = interrupt.clone();
     let signal_handlers_clone = signal_handlers.clone();
+    let pause_flag_clone = pause_flag.clone();
+    let signals_clone = signals.clone();
+    let signals_for_keys = signals.clone();
 
-    // Start a thread to listen for signals like SIGINT, SIGTERM, SIGTSTP
+    // Start a thread to listen for signals like SIGINT, SIGTERM, SIGTSTP, SIGCONT.
+    // `SignalsInfo<WithOrigin>` (rather than the plain `Signals` iterator) also
+    // exfiltrates the sending PID/UID, so handlers can tell apart, e.g., a
+    // SIGTERM from a service manager versus an operator's Ctrl-C.
     thread::spawn(move || {
-        if let Ok(mut signals) = SignalHook::new([SIGINT, SIGTERM, SIGTSTP]) {
-            for signal in signals.forever() {
-                match signal {
+        if let Ok(mut signals) =
+            SignalsInfo::<WithOrigin>::new([SIGINT, SIGTERM, SIGTSTP, SIGCONT, SIGHUP])
+        {
+            for info in signals.forever() {
+                let origin = Some(signal_origin(&info));
+                // Entering a scope for the duration of each dispatched handler
+                // means a handler that itself calls `Signals::reset` (e.g. a
+                // reload handler clearing its own state) can't swallow a
+                // signal meant for the pipeline this handler was dispatched
+                // on top of.
+                let _scope = signals_clone.scope();
+                match info.signal {
                     SIGTERM => {
                         pause_flag.store(true, Ordering::Relaxed);
-                        signal_handlers.run(SignalAction::Pause);
+                        signal_handlers.run(SignalAction::Pause(origin));
                     }
                     SIGINT => {
+                        let mut pending = PENDING_INTERRUPT.lock().unwrap();
+                        let now = Instant::now();
+                        let double_tap = is_unacknowledged_double_tap(
+                            *pending,
+                            interrupt_clone.load(Ordering::Relaxed),
+                            now,
+                        );
+                        *pending = Some(now);
+                        drop(pending);
+
+                        if double_tap {
+                            // The engine never acknowledged the first Ctrl-C via
+                            // `Signals::reset`, so the pipeline is likely wedged.
+                            force_shutdown();
+                        }
+
                         interrupt_clone.store(true, Ordering::Relaxed);
-                        signal_handlers.run(SignalAction::Interrupt);
+                        signal_handlers.run(SignalAction::Interrupt(origin));
                     }
                     SIGTSTP => {
-                        interrupt_clone.store(true, Ordering::Relaxed);
-                        signal_handlers.run(SignalAction::Pause);
+                        pause_flag.store(true, Ordering::Relaxed);
+                        signal_handlers.run(SignalAction::Pause(origin));
+                        // Actually stop the process group so the terminal
+                        // returns to the parent shell, like other job-control
+                        // aware shells. Note: this thread is the only one that
+                        // ever dequeues SIGCONT, so it must not itself wait on
+                        // `wait_if_paused` here — that's for other, cooperative
+                        // threads/commands to park on instead.
+                        suspend_to_background();
+                    }
+                    SIGCONT => {
+                        // We only get here after a real suspend; restore raw
+                        // mode/redraw and let a resume handler run. Go through
+                        // `trigger_resume` (not a raw store) so any thread
+                        // parked in `wait_if_paused` actually wakes up.
+                        signals_clone.trigger_resume();
+                        resume_from_background();
+                        signal_handlers.run(SignalAction::Resume(origin));
+                    }
+                    SIGHUP => {
+                        // Let a registered handler re-read env/config and re-source it;
+                        // the running pipeline is left untouched.
+                        signal_handlers.run(SignalAction::Reload(origin));
                     }
                     _ => unreachable!(),
                 }
@@ -67,10 +187,50 @@ pub(crate) fn ctrl_protection(engine_state: &mut EngineState) {
             }) = event::read().unwrap()
             {
                 if code == KeyCode::Char('z') && modifiers == KeyModifiers::CONTROL {
-                    interrupt.store(true, Ordering::Relaxed);
-                    signal_handlers_clone.run(SignalAction::Pause);
+                    let _scope = signals_for_keys.scope();
+                    pause_flag_clone.store(true, Ordering::Relaxed);
+                    signal_handlers_clone.run(SignalAction::Pause(None));
+                    // Raw mode disables the terminal's own SIGTSTP generation,
+                    // so raise it ourselves to get a genuine suspend.
+                    suspend_to_background();
+                    // Unlike the signal-listening thread, this thread isn't
+                    // the one that dequeues SIGCONT and calls `trigger_resume`
+                    // — so parking here is safe, and keeps this thread from
+                    // resuming its key poll loop until that's actually run.
+                    signals_for_keys.wait_if_paused();
                 }
             }
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_interrupt_is_never_a_double_tap() {
+        assert!(!is_unacknowledged_double_tap(None, true, Instant::now()));
+    }
+
+    #[test]
+    fn second_interrupt_within_window_while_unacknowledged_is_a_double_tap() {
+        let first = Instant::now();
+        let second = first + Duration::from_millis(10);
+        assert!(is_unacknowledged_double_tap(Some(first), true, second));
+    }
+
+    #[test]
+    fn second_interrupt_is_not_a_double_tap_once_engine_acknowledged() {
+        let first = Instant::now();
+        let second = first + Duration::from_millis(10);
+        assert!(!is_unacknowledged_double_tap(Some(first), false, second));
+    }
+
+    #[test]
+    fn second_interrupt_outside_window_is_not_a_double_tap() {
+        let first = Instant::now();
+        let second = first + DOUBLE_TAP_WINDOW + Duration::from_millis(1);
+        assert!(!is_unacknowledged_double_tap(Some(first), true, second));
+    }
+}